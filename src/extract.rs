@@ -2,15 +2,120 @@
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
-use syn::{Type, Field, ItemStruct, ItemEnum, ItemTrait};
+use syn::{Type, ItemStruct, ItemEnum, ItemTrait, ItemType};
+
+/// Serde container rename rule (`#[serde(rename_all = "...")]`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RenameRule {
+    CamelCase,
+    SnakeCase,
+    PascalCase,
+    ScreamingSnakeCase,
+    KebabCase,
+}
+
+impl RenameRule {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "camelCase" => Some(RenameRule::CamelCase),
+            "snake_case" => Some(RenameRule::SnakeCase),
+            "PascalCase" => Some(RenameRule::PascalCase),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnakeCase),
+            "kebab-case" => Some(RenameRule::KebabCase),
+            _ => None,
+        }
+    }
+
+    /// Apply the rule to a Rust ident, matching serde's wire output.
+    ///
+    /// The ident is first decomposed into lowercase words, honoring both
+    /// `_`/`-` separators and internal case boundaries, so this works whether
+    /// the source is a `snake_case` field (`first_name`) or a `PascalCase`
+    /// variant (`InProgress`).
+    fn apply(&self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            RenameRule::SnakeCase => words.join("_"),
+            RenameRule::ScreamingSnakeCase => words.join("_").to_uppercase(),
+            RenameRule::KebabCase => words.join("-"),
+            RenameRule::CamelCase => {
+                let mut out = String::new();
+                for (i, word) in words.iter().enumerate() {
+                    if i == 0 {
+                        out.push_str(word);
+                    } else {
+                        out.push_str(&capitalize(word));
+                    }
+                }
+                out
+            }
+            RenameRule::PascalCase => words.iter().map(|w| capitalize(w)).collect(),
+        }
+    }
+}
+
+/// Split an identifier into lowercase words on `_`/`-` separators and before
+/// every uppercase letter (as serde does for variants, so `HTTPError` becomes
+/// `h`, `t`, `t`, `p`, `error`), so both `snake_case` fields and `PascalCase`
+/// variants decompose correctly.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for ch in name.chars() {
+        if ch == '_' || ch == '-' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if ch.is_uppercase() && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words.into_iter().map(|w| w.to_lowercase()).collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Per-field / per-variant serde attributes that affect generation.
+#[derive(Debug, Default, Clone)]
+struct SerdeFieldAttrs {
+    rename: Option<String>,
+    skip: bool,
+    optional: bool,
+}
 
 /// Options for the extraction process
 #[derive(Debug, Clone)]
 pub struct ExtractOptions {
+    /// Inline referenced interfaces as anonymous object literals instead of
+    /// named references.
     pub embed_structs: bool,
+    /// Pull referenced named types into the output. Implicit for single-file
+    /// extraction (every item in the source is already extracted), so this flag
+    /// has no additional effect today; kept for API/forward compatibility.
     pub follow_structs: bool,
     pub no_anon_structs: bool,
     pub sort_alphabetically: bool,
+    /// Emit `Record<string, V>` for maps instead of `{ [key: string]: V }`.
+    pub use_record_type: bool,
+    /// Convert `snake_case` field names to `camelCase` when no serde rename is
+    /// present. Off by default, since serde preserves the Rust name on the wire
+    /// unless a `rename`/`rename_all` says otherwise.
+    pub camel_case_fields: bool,
+    /// User-supplied overrides mapping a Rust type (by last segment or full
+    /// path) to a fixed TypeScript type, e.g. `DateTime` -> `string`.
+    pub type_overrides: HashMap<String, String>,
 }
 
 impl Default for ExtractOptions {
@@ -20,6 +125,9 @@ impl Default for ExtractOptions {
             follow_structs: false,
             no_anon_structs: false,
             sort_alphabetically: false,
+            use_record_type: false,
+            camel_case_fields: false,
+            type_overrides: HashMap::new(),
         }
     }
 }
@@ -30,18 +138,82 @@ pub enum TypescriptType {
     Interface {
         name: String,
         fields: Vec<TypescriptField>,
+        generics: Vec<String>,
         doc: Option<String>,
     },
     Enum {
         name: String,
-        variants: Vec<String>,
+        variants: Vec<TypescriptVariant>,
+        tagging: EnumTagging,
+        generics: Vec<String>,
         doc: Option<String>,
     },
     Trait {
         name: String,
         methods: Vec<TypescriptMethod>,
+        generics: Vec<String>,
         doc: Option<String>,
     },
+    TypeAlias {
+        name: String,
+        target: String,
+        generics: Vec<String>,
+        doc: Option<String>,
+    },
+}
+
+/// An explicit enum discriminant value (`Ok = 200`, `Mode = "fast"`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EnumValue {
+    String(String),
+    Number(i64),
+    Identifier(String),
+}
+
+impl EnumValue {
+    /// Render the value as it appears on the right-hand side in TypeScript.
+    pub fn to_typescript(&self) -> String {
+        match self {
+            EnumValue::String(s) => format!("\"{}\"", s),
+            EnumValue::Number(n) => n.to_string(),
+            EnumValue::Identifier(i) => i.clone(),
+        }
+    }
+}
+
+/// A single enum variant, carrying its (renamed) discriminant and payload shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypescriptVariant {
+    /// Discriminant string as it appears on the wire (after serde rename rules).
+    pub name: String,
+    /// Explicit discriminant value from the Rust source (`Variant = 200`), if any.
+    pub value: Option<EnumValue>,
+    pub fields: VariantFields,
+    pub doc: Option<String>,
+}
+
+/// Payload carried by an enum variant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum VariantFields {
+    /// A unit variant with no payload (`Ping`).
+    Unit,
+    /// A struct variant with named fields (`Text { body: String }`).
+    Named(Vec<TypescriptField>),
+    /// A tuple variant with positional fields (`Ping(u32)`).
+    Unnamed(Vec<String>),
+}
+
+/// How serde tags enum variants on the wire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EnumTagging {
+    /// serde default: `{ "Variant": payload }`.
+    External,
+    /// `#[serde(tag = "...")]`: `{ "type": "Variant", ..fields }`.
+    Internal { tag: String },
+    /// `#[serde(tag = "...", content = "...")]`: `{ "t": "Variant", "c": payload }`.
+    Adjacent { tag: String, content: String },
+    /// `#[serde(untagged)]`: a bare union of the variant shapes.
+    Untagged,
 }
 
 /// TypeScript field representation
@@ -73,6 +245,8 @@ pub struct TypescriptParam {
 pub struct Extractor {
     options: ExtractOptions,
     result: HashMap<String, TypescriptType>,
+    /// Type parameters in scope for the item currently being converted.
+    current_generics: Vec<String>,
 }
 
 impl Extractor {
@@ -80,6 +254,7 @@ impl Extractor {
         Self {
             options,
             result: HashMap::new(),
+            current_generics: Vec::new(),
         }
     }
 
@@ -98,23 +273,85 @@ impl Extractor {
                 syn::Item::Trait(item_trait) => {
                     self.extract_trait(&item_trait)?;
                 }
+                syn::Item::Type(item_type) => {
+                    self.extract_type_alias(&item_type)?;
+                }
                 _ => {}
             }
         }
 
+        self.resolve_dependencies();
+
         Ok(self.result.clone())
     }
 
+    /// Apply `embed_structs` inlining of referenced interfaces.
+    ///
+    /// `follow_structs` requires no work here: extraction walks every item in
+    /// the source file, so any referenced named type is already extracted, and
+    /// the generator's topological ordering keeps declarations before use.
+    /// Following is therefore implicit for single-file input. When
+    /// `embed_structs` is set, each field whose type is another interface's
+    /// name, optionally followed by one `[]`, is inlined as an anonymous object
+    /// literal; self-references are left as named references to avoid unbounded
+    /// inlining. References nested inside other types (maps, tuples, generic
+    /// arguments) stay named, and inlining is one level deep: the inlined body
+    /// keeps its own references named.
+    fn resolve_dependencies(&mut self) {
+        if self.options.embed_structs {
+            self.embed_references();
+        }
+    }
+
+    /// Inline referenced interface bodies as anonymous object literals.
+    fn embed_references(&mut self) {
+        let mut bodies: HashMap<String, String> = HashMap::new();
+        for (name, ty) in &self.result {
+            if let TypescriptType::Interface { fields, .. } = ty {
+                bodies.insert(name.clone(), object_literal(fields));
+            }
+        }
+
+        for (name, ty) in self.result.iter_mut() {
+            if let TypescriptType::Interface { fields, .. } = ty {
+                for field in fields.iter_mut() {
+                    let (base, suffix) = split_array_suffix(&field.ts_type);
+                    if base != name.as_str() {
+                        if let Some(body) = bodies.get(base) {
+                            field.ts_type = format!("{}{}", body, suffix);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn extract_struct(&mut self, item: &ItemStruct) -> Result<(), Box<dyn std::error::Error>> {
         let name = item.ident.to_string();
+        let rename_all = self.container_rename_all(&item.attrs);
+        self.current_generics = collect_generic_idents(&item.generics);
+        let generics = render_generics(&item.generics);
         let mut fields = Vec::new();
 
         for field in &item.fields {
             if let Some(field_name) = &field.ident {
+                let attrs = self.serde_field_attrs(&field.attrs);
+                if attrs.skip {
+                    continue;
+                }
+
+                let name = if let Some(rename) = attrs.rename {
+                    rename
+                } else if let Some(rule) = rename_all {
+                    rule.apply(&field_name.to_string())
+                } else {
+                    self.default_field_name(field_name.to_string())
+                };
+
                 let ts_field = TypescriptField {
-                    name: self.convert_field_name(field_name.to_string()),
+                    name,
                     ts_type: self.convert_type(&field.ty)?,
-                    optional: self.is_optional_field(field),
+                    optional: self.is_option_type(&field.ty) || attrs.optional,
                     doc: self.extract_doc(&field.attrs),
                 };
                 fields.push(ts_field);
@@ -124,33 +361,98 @@ impl Extractor {
         let ts_type = TypescriptType::Interface {
             name: name.clone(),
             fields,
+            generics,
             doc: self.extract_doc(&item.attrs),
         };
 
+        self.current_generics.clear();
         self.result.insert(name, ts_type);
         Ok(())
     }
 
     fn extract_enum(&mut self, item: &ItemEnum) -> Result<(), Box<dyn std::error::Error>> {
         let name = item.ident.to_string();
+        let rename_all = self.container_rename_all(&item.attrs);
+        let tagging = self.enum_tagging(&item.attrs);
+        self.current_generics = collect_generic_idents(&item.generics);
+        let generics = render_generics(&item.generics);
         let mut variants = Vec::new();
 
         for variant in &item.variants {
-            variants.push(variant.ident.to_string());
+            let attrs = self.serde_field_attrs(&variant.attrs);
+            if attrs.skip {
+                continue;
+            }
+            let variant_name = if let Some(rename) = attrs.rename {
+                rename
+            } else if let Some(rule) = rename_all {
+                rule.apply(&variant.ident.to_string())
+            } else {
+                variant.ident.to_string()
+            };
+
+            let fields = match &variant.fields {
+                syn::Fields::Unit => VariantFields::Unit,
+                syn::Fields::Named(named) => {
+                    let field_rename_all = self.container_rename_all(&variant.attrs);
+                    let mut ts_fields = Vec::new();
+                    for field in &named.named {
+                        if let Some(ident) = &field.ident {
+                            let f_attrs = self.serde_field_attrs(&field.attrs);
+                            if f_attrs.skip {
+                                continue;
+                            }
+                            let f_name = if let Some(rename) = f_attrs.rename {
+                                rename
+                            } else if let Some(rule) = field_rename_all {
+                                rule.apply(&ident.to_string())
+                            } else {
+                                self.default_field_name(ident.to_string())
+                            };
+                            ts_fields.push(TypescriptField {
+                                name: f_name,
+                                ts_type: self.convert_type(&field.ty)?,
+                                optional: self.is_option_type(&field.ty) || f_attrs.optional,
+                                doc: self.extract_doc(&field.attrs),
+                            });
+                        }
+                    }
+                    VariantFields::Named(ts_fields)
+                }
+                syn::Fields::Unnamed(unnamed) => {
+                    let mut elems = Vec::new();
+                    for field in &unnamed.unnamed {
+                        elems.push(self.convert_type(&field.ty)?);
+                    }
+                    VariantFields::Unnamed(elems)
+                }
+            };
+
+            variants.push(TypescriptVariant {
+                name: variant_name,
+                value: extract_variant_value(variant),
+                fields,
+                doc: self.extract_doc(&variant.attrs),
+            });
         }
 
         let ts_type = TypescriptType::Enum {
             name: name.clone(),
             variants,
+            tagging,
+            generics,
             doc: self.extract_doc(&item.attrs),
         };
 
+        self.current_generics.clear();
         self.result.insert(name, ts_type);
         Ok(())
     }
 
     fn extract_trait(&mut self, item: &ItemTrait) -> Result<(), Box<dyn std::error::Error>> {
         let name = item.ident.to_string();
+        self.current_generics = collect_generic_idents(&item.generics);
+        let generics = render_generics(&item.generics);
         let mut methods = Vec::new();
 
         for item in &item.items {
@@ -161,7 +463,12 @@ impl Extractor {
                 for (i, input) in method.sig.inputs.iter().enumerate() {
                     match input {
                         syn::FnArg::Typed(pat_type) => {
-                            let param_name = format!("arg{}", i);
+                            // Recover the real parameter identifier; fall back to
+                            // a positional name only for non-ident patterns.
+                            let param_name = match pat_type.pat.as_ref() {
+                                syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+                                _ => format!("arg{}", i),
+                            };
                             let param_type = self.convert_type(&pat_type.ty)?;
                             params.push(TypescriptParam {
                                 name: param_name,
@@ -173,7 +480,7 @@ impl Extractor {
                 }
 
                 let return_type = match &method.sig.output {
-                    syn::ReturnType::Type(_, ty) => self.convert_type(ty)?,
+                    syn::ReturnType::Type(_, ty) => self.convert_return_type(ty)?,
                     _ => "void".to_string(),
                 };
 
@@ -189,23 +496,106 @@ impl Extractor {
         let ts_type = TypescriptType::Trait {
             name: name.clone(),
             methods,
+            generics,
+            doc: self.extract_doc(&item.attrs),
+        };
+
+        self.current_generics.clear();
+        self.result.insert(name, ts_type);
+        Ok(())
+    }
+
+    fn extract_type_alias(&mut self, item: &ItemType) -> Result<(), Box<dyn std::error::Error>> {
+        let name = item.ident.to_string();
+        self.current_generics = collect_generic_idents(&item.generics);
+        let generics = render_generics(&item.generics);
+        let target = self.convert_type(&item.ty)?;
+
+        let ts_type = TypescriptType::TypeAlias {
+            name: name.clone(),
+            target,
+            generics,
             doc: self.extract_doc(&item.attrs),
         };
 
+        self.current_generics.clear();
         self.result.insert(name, ts_type);
         Ok(())
     }
 
-    fn convert_type(&self, ty: &Type) -> Result<String, Box<dyn std::error::Error>> {
+    /// Convert a method return type, unwrapping a top-level `Result<T, E>` to
+    /// `T` (the error variant is surfaced out of band, e.g. as a rejected
+    /// promise). Unlike plain fields, RPC returns flatten their `Result`.
+    fn convert_return_type(&self, ty: &Type) -> Result<String, Box<dyn std::error::Error>> {
+        if let Type::Path(type_path) = ty {
+            if let Some(segment) = type_path.path.segments.last() {
+                if segment.ident == "Result" {
+                    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                        if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                            return self.convert_type(inner);
+                        }
+                    }
+                }
+            }
+        }
+        self.convert_type(ty)
+    }
+
+    pub(crate) fn convert_type(&self, ty: &Type) -> Result<String, Box<dyn std::error::Error>> {
         match ty {
             Type::Path(type_path) => {
                 let path = &type_path.path;
                 if let Some(segment) = path.segments.last() {
                     let type_name = segment.ident.to_string();
-                    
+
+                    // User-supplied overrides win, matched by last segment or full
+                    // path (`chrono::DateTime`, `uuid::Uuid`, ...).
+                    if let Some(mapped) = self
+                        .options
+                        .type_overrides
+                        .get(&type_name)
+                        .or_else(|| self.options.type_overrides.get(&path_to_string(path)))
+                    {
+                        return Ok(mapped.clone());
+                    }
+
+                    // A bare, argument-free path matching an in-scope type parameter
+                    // passes through verbatim as a TypeScript type variable.
+                    if segment.arguments.is_empty()
+                        && path.segments.len() == 1
+                        && self.current_generics.iter().any(|g| g == &type_name)
+                    {
+                        return Ok(type_name);
+                    }
+
                     // Handle generic types like Option<T>, Vec<T>
                     if !segment.arguments.is_empty() {
                         if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                            // Transparent wrappers collapse to their inner type.
+                            if matches!(type_name.as_str(), "Box" | "Rc" | "Arc" | "Cow") {
+                                for arg in &args.args {
+                                    if let syn::GenericArgument::Type(inner_ty) = arg {
+                                        return self.convert_type(inner_ty);
+                                    }
+                                }
+                            }
+
+                            // Maps become string-keyed records.
+                            if matches!(type_name.as_str(), "HashMap" | "BTreeMap") {
+                                let value = args.args.iter().filter_map(|a| match a {
+                                    syn::GenericArgument::Type(t) => Some(t),
+                                    _ => None,
+                                });
+                                if let Some(value_ty) = value.last() {
+                                    let v = self.convert_type(value_ty)?;
+                                    return Ok(if self.options.use_record_type {
+                                        format!("Record<string, {}>", v)
+                                    } else {
+                                        format!("{{ [key: string]: {} }}", v)
+                                    });
+                                }
+                            }
+
                             if type_name == "Option" && args.args.len() == 1 {
                                 if let syn::GenericArgument::Type(inner_ty) = &args.args[0] {
                                     let inner_type = self.convert_type(inner_ty)?;
@@ -217,14 +607,49 @@ impl Extractor {
                                     return Ok(format!("{}[]", inner_type));
                                 }
                             }
+
+                            // Other generic references (`Foo<Bar>`) carry their
+                            // arguments through via TsType::Generic.
+                            let mut ts_args = Vec::new();
+                            for arg in &args.args {
+                                if let syn::GenericArgument::Type(inner_ty) = arg {
+                                    ts_args.push(crate::typescript::TsType::Reference(
+                                        self.convert_type(inner_ty)?,
+                                    ));
+                                }
+                            }
+                            if !ts_args.is_empty() {
+                                let base = self.rust_to_typescript_type(&type_name);
+                                return Ok(crate::typescript::TsType::Generic {
+                                    base,
+                                    args: ts_args,
+                                }
+                                .to_typescript());
+                            }
                         }
                     }
-                    
+
                     Ok(self.rust_to_typescript_type(&type_name))
                 } else {
                     Ok("any".to_string())
                 }
             }
+            Type::Tuple(tuple) => {
+                if tuple.elems.is_empty() {
+                    // The unit type serializes to JSON `null`.
+                    return Ok("null".to_string());
+                }
+                let mut parts = Vec::new();
+                for elem in &tuple.elems {
+                    parts.push(self.convert_type(elem)?);
+                }
+                Ok(format!("[{}]", parts.join(", ")))
+            }
+            Type::Array(array) => {
+                // Fixed-size arrays `[T; N]` map to `T[]`.
+                let inner = self.convert_type(&array.elem)?;
+                Ok(format!("{}[]", inner))
+            }
             _ => Ok("any".to_string()),
         }
     }
@@ -240,6 +665,16 @@ impl Extractor {
         }
     }
 
+    /// The field name to emit when no serde rename applies: the Rust name
+    /// verbatim (serde's default), or camelCased when `camel_case_fields` is set.
+    fn default_field_name(&self, name: String) -> String {
+        if self.options.camel_case_fields {
+            self.convert_field_name(name)
+        } else {
+            name
+        }
+    }
+
     fn convert_field_name(&self, name: String) -> String {
         // Convert snake_case to camelCase for JSON compatibility
         let parts: Vec<&str> = name.split('_').collect();
@@ -260,42 +695,264 @@ impl Extractor {
         result
     }
 
-    fn is_optional_field(&self, field: &Field) -> bool {
-        // Check if field type is Option<T>
-        if let Type::Path(type_path) = &field.ty {
+    /// Whether a type is `Option<T>` (which serializes as an optional field).
+    fn is_option_type(&self, ty: &Type) -> bool {
+        if let Type::Path(type_path) = ty {
             if let Some(segment) = type_path.path.segments.last() {
-                if segment.ident == "Option" {
-                    return true;
-                }
+                return segment.ident == "Option";
             }
         }
-        
-        // Check for serde skip_serializing_if attribute
-        for attr in &field.attrs {
-            if attr.path().is_ident("serde") {
-                // This is a simplified check - in practice you'd parse the serde attributes more thoroughly
-                let attr_str = format!("{:?}", attr);
-                if attr_str.contains("skip_serializing_if") {
-                    return true;
+        false
+    }
+
+    /// Parse the container-level `#[serde(rename_all = "...")]` rule, if any.
+    fn container_rename_all(&self, attrs: &[syn::Attribute]) -> Option<RenameRule> {
+        let mut rule = None;
+        for attr in attrs {
+            if !attr.path().is_ident("serde") {
+                continue;
+            }
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    let value = meta.value()?;
+                    let lit: syn::LitStr = value.parse()?;
+                    rule = RenameRule::from_str(&lit.value());
                 }
+                Ok(())
+            });
+        }
+        rule
+    }
+
+    /// Determine how serde tags the variants of an enum.
+    fn enum_tagging(&self, attrs: &[syn::Attribute]) -> EnumTagging {
+        let mut tag = None;
+        let mut content = None;
+        let mut untagged = false;
+        for attr in attrs {
+            if !attr.path().is_ident("serde") {
+                continue;
             }
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    let value = meta.value()?;
+                    let lit: syn::LitStr = value.parse()?;
+                    tag = Some(lit.value());
+                } else if meta.path.is_ident("content") {
+                    let value = meta.value()?;
+                    let lit: syn::LitStr = value.parse()?;
+                    content = Some(lit.value());
+                } else if meta.path.is_ident("untagged") {
+                    untagged = true;
+                }
+                Ok(())
+            });
+        }
+        match (untagged, tag, content) {
+            (true, _, _) => EnumTagging::Untagged,
+            (false, Some(tag), Some(content)) => EnumTagging::Adjacent { tag, content },
+            (false, Some(tag), None) => EnumTagging::Internal { tag },
+            (false, None, _) => EnumTagging::External,
         }
+    }
 
-        false
+    /// Parse the per-field/variant serde attributes relevant to generation.
+    fn serde_field_attrs(&self, attrs: &[syn::Attribute]) -> SerdeFieldAttrs {
+        let mut parsed = SerdeFieldAttrs::default();
+        for attr in attrs {
+            if !attr.path().is_ident("serde") {
+                continue;
+            }
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let value = meta.value()?;
+                    let lit: syn::LitStr = value.parse()?;
+                    parsed.rename = Some(lit.value());
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                    parsed.skip = true;
+                } else if meta.path.is_ident("default")
+                    || meta.path.is_ident("skip_serializing_if")
+                {
+                    parsed.optional = true;
+                    // `default`/`skip_serializing_if` may carry a value (`= "..."`);
+                    // consume it fully so parsing of the remaining metas continues.
+                    if meta.input.peek(syn::Token![=]) {
+                        let value = meta.value()?;
+                        let _: syn::LitStr = value.parse()?;
+                    }
+                }
+                Ok(())
+            });
+        }
+        parsed
     }
 
     fn extract_doc(&self, attrs: &[syn::Attribute]) -> Option<String> {
+        let mut lines = Vec::new();
         for attr in attrs {
             if attr.path().is_ident("doc") {
                 if let Ok(meta) = attr.meta.require_name_value() {
                     if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit_str), .. }) = &meta.value {
-                        return Some(lit_str.value().trim().to_string());
+                        let raw = lit_str.value();
+                        // Drop the single leading space rustdoc `///` inserts.
+                        lines.push(raw.strip_prefix(' ').unwrap_or(&raw).to_string());
                     }
                 }
             }
         }
-        None
+
+        // Trim trailing blank lines so the JSDoc block does not end empty.
+        while lines.last().map(|l| l.trim().is_empty()).unwrap_or(false) {
+            lines.pop();
+        }
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+}
+
+/// Extract candidate named-type identifiers from a rendered TypeScript type.
+///
+/// Used to build the inter-type reference graph; built-in TypeScript keywords
+/// and primitives are filtered out by the caller against the set of known types.
+pub(crate) fn referenced_type_names(ts_type: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current = String::new();
+    for ch in ts_type.chars() {
+        if ch.is_alphanumeric() || ch == '_' {
+            current.push(ch);
+        } else {
+            if !current.is_empty() {
+                names.push(std::mem::take(&mut current));
+            }
+        }
     }
+    if !current.is_empty() {
+        names.push(current);
+    }
+    names
+}
+
+/// Render an interface's fields as an anonymous object literal type.
+fn object_literal(fields: &[TypescriptField]) -> String {
+    let props: Vec<String> = fields
+        .iter()
+        .map(|f| {
+            let optional = if f.optional { "?" } else { "" };
+            format!("{}{}: {}", f.name, optional, f.ts_type)
+        })
+        .collect();
+    format!("{{ {} }}", props.join("; "))
+}
+
+/// Split a trailing `[]` array suffix off a TypeScript type string.
+fn split_array_suffix(ts_type: &str) -> (&str, &str) {
+    if let Some(base) = ts_type.strip_suffix("[]") {
+        (base, "[]")
+    } else {
+        (ts_type, "")
+    }
+}
+
+/// Unescape sequences that Rust/markdown introduce into doc comments so the
+/// rendered JSDoc reads correctly: backslash escapes (`\"`, `\\`, `\n`, `\t`)
+/// and the common HTML entities.
+pub(crate) fn unescape_doc(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            match chars.peek() {
+                Some('"') => { out.push('"'); chars.next(); }
+                Some('\\') => { out.push('\\'); chars.next(); }
+                Some('n') => { out.push('\n'); chars.next(); }
+                Some('t') => { out.push('\t'); chars.next(); }
+                _ => out.push('\\'),
+            }
+        } else {
+            out.push(ch);
+        }
+    }
+
+    out.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Read a variant's explicit discriminant (`= 200` / `= "fast"`), if present.
+fn extract_variant_value(variant: &syn::Variant) -> Option<EnumValue> {
+    let (_, expr) = variant.discriminant.as_ref()?;
+    match expr {
+        syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+            syn::Lit::Str(lit_str) => Some(EnumValue::String(lit_str.value())),
+            syn::Lit::Int(lit_int) => lit_int.base10_parse::<i64>().ok().map(EnumValue::Number),
+            _ => None,
+        },
+        syn::Expr::Path(expr_path) => expr_path
+            .path
+            .get_ident()
+            .map(|ident| EnumValue::Identifier(ident.to_string())),
+        _ => None,
+    }
+}
+
+/// Render a path as its `::`-joined segment idents (without generic arguments).
+fn path_to_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Collect declared type-parameter idents, ignoring lifetimes, const generics,
+/// and any trait bounds. Used to recognise in-scope type variables.
+fn collect_generic_idents(generics: &syn::Generics) -> Vec<String> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(type_param) => Some(type_param.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Render declared type parameters for a header, mapping trait bounds that
+/// translate cleanly to a TypeScript `extends` constraint (`T: Display` ->
+/// `T extends Display`) and emitting the bare parameter otherwise.
+fn render_generics(generics: &syn::Generics) -> Vec<String> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(type_param) => {
+                let bounds: Vec<String> = type_param
+                    .bounds
+                    .iter()
+                    .filter_map(|bound| match bound {
+                        syn::TypeParamBound::Trait(trait_bound) => trait_bound
+                            .path
+                            .segments
+                            .last()
+                            .map(|seg| seg.ident.to_string()),
+                        _ => None,
+                    })
+                    .collect();
+                if bounds.is_empty() {
+                    Some(type_param.ident.to_string())
+                } else {
+                    Some(format!("{} extends {}", type_param.ident, bounds.join(" & ")))
+                }
+            }
+            _ => None,
+        })
+        .collect()
 }
 
 /// Extract TypeScript types from Rust source code
@@ -333,4 +990,260 @@ mod tests {
         let result = extract(source, ExtractOptions::default()).unwrap();
         assert!(result.contains_key("Status"));
     }
+
+    #[test]
+    fn test_serde_rename_all_and_rename() {
+        let source = r#"
+            #[serde(rename_all = "camelCase")]
+            pub struct Demo {
+                pub first_name: String,
+                #[serde(rename = "ID")]
+                pub id: u32,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Interface { fields, .. }) = result.get("Demo") {
+            assert_eq!(fields[0].name, "firstName");
+            assert_eq!(fields[1].name, "ID");
+        } else {
+            panic!("expected interface");
+        }
+    }
+
+    #[test]
+    fn test_rename_all_variants() {
+        // Variants are PascalCase, so the rule must split on case boundaries.
+        let source = r#"
+            #[serde(rename_all = "snake_case")]
+            pub enum State {
+                InProgress,
+                Done,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Enum { variants, .. }) = result.get("State") {
+            assert_eq!(variants[0].name, "in_progress");
+            assert_eq!(variants[1].name, "done");
+        } else {
+            panic!("expected enum");
+        }
+    }
+
+    #[test]
+    fn test_default_preserves_snake_case() {
+        // Without a rename, serde keeps the Rust name, so must bel by default.
+        let source = r#"
+            pub struct Demo {
+                pub first_name: String,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Interface { fields, .. }) = result.get("Demo") {
+            assert_eq!(fields[0].name, "first_name");
+        } else {
+            panic!("expected interface");
+        }
+
+        let mut options = ExtractOptions::default();
+        options.camel_case_fields = true;
+        let result = extract(source, options).unwrap();
+        if let Some(TypescriptType::Interface { fields, .. }) = result.get("Demo") {
+            assert_eq!(fields[0].name, "firstName");
+        } else {
+            panic!("expected interface");
+        }
+    }
+
+    #[test]
+    fn test_enum_explicit_discriminant_captured() {
+        let source = r#"
+            pub enum Code {
+                Ok = 200,
+                NotFound = 404,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Enum { variants, .. }) = result.get("Code") {
+            assert!(matches!(variants[0].value, Some(EnumValue::Number(200))));
+            assert!(matches!(variants[1].value, Some(EnumValue::Number(404))));
+        } else {
+            panic!("expected enum");
+        }
+    }
+
+    #[test]
+    fn test_generic_struct() {
+        let source = r#"
+            pub struct Page<T> {
+                pub items: Vec<T>,
+                pub next: Option<String>,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Interface { generics, fields, .. }) = result.get("Page") {
+            assert_eq!(generics, &vec!["T".to_string()]);
+            assert_eq!(fields[0].ts_type, "T[]");
+        } else {
+            panic!("expected interface");
+        }
+    }
+
+    #[test]
+    fn test_convert_type_maps_tuples_and_overrides() {
+        let source = r#"
+            pub struct Demo {
+                pub meta: HashMap<String, u32>,
+                pub pair: (String, u32),
+                pub buf: [u8; 16],
+                pub boxed: Box<String>,
+                pub when: DateTime<Utc>,
+            }
+        "#;
+
+        let mut options = ExtractOptions::default();
+        options.type_overrides.insert("DateTime".to_string(), "string".to_string());
+
+        let result = extract(source, options).unwrap();
+        if let Some(TypescriptType::Interface { fields, .. }) = result.get("Demo") {
+            assert_eq!(fields[0].ts_type, "{ [key: string]: number }");
+            assert_eq!(fields[1].ts_type, "[string, number]");
+            assert_eq!(fields[2].ts_type, "number[]");
+            assert_eq!(fields[3].ts_type, "string");
+            assert_eq!(fields[4].ts_type, "string");
+        } else {
+            panic!("expected interface");
+        }
+    }
+
+    #[test]
+    fn test_result_unwrap_scoped_to_return_types() {
+        // A struct field typed `Result<T, E>` must NOT be silently flattened...
+        let struct_src = r#"
+            pub struct Demo {
+                pub outcome: Result<u32, String>,
+            }
+        "#;
+        let result = extract(struct_src, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Interface { fields, .. }) = result.get("Demo") {
+            assert_eq!(fields[0].ts_type, "Result<number, string>");
+        } else {
+            panic!("expected interface");
+        }
+
+        // ...but a method return type is flattened to its `Ok` type.
+        let trait_src = r#"
+            pub trait Api {
+                fn fetch(&self) -> Result<u32, String>;
+            }
+        "#;
+        let result = extract(trait_src, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Trait { methods, .. }) = result.get("Api") {
+            assert_eq!(methods[0].return_type, "number");
+        } else {
+            panic!("expected trait");
+        }
+    }
+
+    #[test]
+    fn test_unescape_doc() {
+        assert_eq!(unescape_doc(r#"say \"hi\""#), "say \"hi\"");
+        assert_eq!(unescape_doc("a &amp; b &lt;T&gt;"), "a & b <T>");
+    }
+
+    #[test]
+    fn test_multiline_doc_comment() {
+        let source = r#"
+            /// First line.
+            /// Second line.
+            pub struct Demo {
+                pub foo: String,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Interface { doc, .. }) = result.get("Demo") {
+            assert_eq!(doc.as_deref(), Some("First line.\nSecond line."));
+        } else {
+            panic!("expected interface");
+        }
+    }
+
+    #[test]
+    fn test_embed_structs_inlines_references() {
+        let source = r#"
+            pub struct Outer {
+                pub inner: Inner,
+            }
+            pub struct Inner {
+                pub value: u32,
+            }
+        "#;
+
+        let mut options = ExtractOptions::default();
+        options.embed_structs = true;
+
+        let result = extract(source, options).unwrap();
+        if let Some(TypescriptType::Interface { fields, .. }) = result.get("Outer") {
+            assert_eq!(fields[0].ts_type, "{ value: number }");
+        } else {
+            panic!("expected interface");
+        }
+    }
+
+    #[test]
+    fn test_type_alias_with_generics() {
+        let source = r#"
+            pub type Pair<T> = (T, T);
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::TypeAlias { target, generics, .. }) = result.get("Pair") {
+            assert_eq!(generics, &vec!["T".to_string()]);
+            assert_eq!(target, "[T, T]");
+        } else {
+            panic!("expected type alias");
+        }
+    }
+
+    #[test]
+    fn test_generic_bound_rendered() {
+        let source = r#"
+            pub struct Wrapper<T: Display> {
+                pub value: T,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Interface { generics, .. }) = result.get("Wrapper") {
+            assert_eq!(generics, &vec!["T extends Display".to_string()]);
+        } else {
+            panic!("expected interface");
+        }
+    }
+
+    #[test]
+    fn test_serde_skip_and_optional() {
+        let source = r#"
+            pub struct Demo {
+                #[serde(skip)]
+                pub internal: String,
+                #[serde(default)]
+                pub maybe: u32,
+            }
+        "#;
+
+        let result = extract(source, ExtractOptions::default()).unwrap();
+        if let Some(TypescriptType::Interface { fields, .. }) = result.get("Demo") {
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].name, "maybe");
+            assert!(fields[0].optional);
+        } else {
+            panic!("expected interface");
+        }
+    }
 }
@@ -2,7 +2,32 @@
 
 use std::collections::HashMap;
 use std::io::Write;
-use crate::extract::{TypescriptType, TypescriptField, TypescriptMethod};
+use crate::extract::{
+    EnumTagging, TypescriptField, TypescriptMethod, TypescriptType, TypescriptVariant,
+    VariantFields,
+};
+use crate::typescript::{TsProperty, TsType};
+
+/// An external formatter command the generated source is piped through.
+#[derive(Debug, Clone)]
+pub struct FormatterCommand {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl FormatterCommand {
+    /// `npx prettier --parser typescript`.
+    pub fn prettier() -> Self {
+        Self {
+            program: "npx".to_string(),
+            args: vec![
+                "prettier".to_string(),
+                "--parser".to_string(),
+                "typescript".to_string(),
+            ],
+        }
+    }
+}
 
 /// Generator options
 #[derive(Debug, Clone)]
@@ -10,7 +35,22 @@ pub struct GeneratorOptions {
     pub namespace: Option<String>,
     pub preamble: Option<String>,
     pub generate_enums_as_sum_types: bool,
+    /// Emit unit enums as a `const` object plus a derived union type instead of
+    /// a TypeScript `enum` (the idiomatic erasable pattern). Takes precedence
+    /// over `generate_enums_as_sum_types`.
+    pub generate_enums_as_const: bool,
     pub sort_alphabetically: bool,
+    /// Emit a concrete JSON-RPC client class per trait instead of an interface.
+    pub generate_rpc_client: bool,
+    /// Send JSON-RPC params by name (an object) rather than positionally (an array).
+    pub rpc_params_by_name: bool,
+    /// When set, render data-carrying enums as internally-tagged discriminated
+    /// unions using this discriminant property name (e.g. `"kind"`), overriding
+    /// the serde-derived tagging. Typically `"kind"` or `"tag"`.
+    pub tagged_union_discriminant: Option<String>,
+    /// When set, the generated source is piped through this formatter before it
+    /// reaches the writer (falling back to the unformatted source on failure).
+    pub formatter: Option<FormatterCommand>,
 }
 
 impl Default for GeneratorOptions {
@@ -19,7 +59,12 @@ impl Default for GeneratorOptions {
             namespace: None,
             preamble: Some("// generated using bel\n// DO NOT MODIFY".to_string()),
             generate_enums_as_sum_types: false,
+            generate_enums_as_const: false,
             sort_alphabetically: false,
+            generate_rpc_client: false,
+            rpc_params_by_name: false,
+            tagged_union_discriminant: None,
+            formatter: None,
         }
     }
 }
@@ -36,6 +81,22 @@ impl Generator {
 
     /// Generate TypeScript code from extracted types
     pub fn generate<W: Write>(&self, types: &HashMap<String, TypescriptType>, writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
+        // Buffer the source so it can optionally be piped through a formatter
+        // before reaching the writer.
+        let mut buffer: Vec<u8> = Vec::new();
+        self.generate_source(types, &mut buffer)?;
+
+        match &self.options.formatter {
+            Some(command) => {
+                let formatted = format_source(&buffer, command);
+                writer.write_all(&formatted)?;
+            }
+            None => writer.write_all(&buffer)?,
+        }
+        Ok(())
+    }
+
+    fn generate_source<W: Write>(&self, types: &HashMap<String, TypescriptType>, writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
         // Write preamble
         if let Some(preamble) = &self.options.preamble {
             writeln!(writer, "{}", preamble)?;
@@ -46,14 +107,18 @@ impl Generator {
             writeln!(writer, "export namespace {} {{", namespace)?;
         }
 
-        // Sort types if requested
-        let mut type_names: Vec<_> = types.keys().collect();
-        if self.options.sort_alphabetically {
-            type_names.sort();
-        }
+        // Order types: alphabetical if requested, otherwise topological so an
+        // interface is declared before any interface whose fields reference it.
+        let type_names = if self.options.sort_alphabetically {
+            let mut names: Vec<String> = types.keys().cloned().collect();
+            names.sort();
+            names
+        } else {
+            topological_order(types)
+        };
 
         // Generate each type
-        for type_name in type_names {
+        for type_name in &type_names {
             if let Some(ts_type) = types.get(type_name) {
                 self.generate_type(ts_type, writer)?;
                 writeln!(writer)?;
@@ -70,9 +135,9 @@ impl Generator {
 
     fn generate_type<W: Write>(&self, ts_type: &TypescriptType, writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
         match ts_type {
-            TypescriptType::Interface { name, fields, doc } => {
+            TypescriptType::Interface { name, fields, generics, doc } => {
                 self.write_doc(doc, writer)?;
-                writeln!(writer, "export interface {} {{", name)?;
+                writeln!(writer, "export interface {}{} {{", name, generic_suffix(generics))?;
                 
                 let mut field_names: Vec<_> = fields.iter().collect();
                 if self.options.sort_alphabetically {
@@ -84,27 +149,43 @@ impl Generator {
                 }
                 writeln!(writer, "}}")?;
             }
-            TypescriptType::Enum { name, variants, doc } => {
+            TypescriptType::Enum { name, variants, tagging, generics, doc } => {
                 self.write_doc(doc, writer)?;
-                if self.options.generate_enums_as_sum_types {
-                    self.generate_sum_type_enum(name, variants, writer)?;
+                if variants.iter().all(|v| matches!(v.fields, VariantFields::Unit)) {
+                    // All unit variants: keep the scalar enum / string sum-type forms.
+                    if self.options.generate_enums_as_const {
+                        self.generate_const_enum(name, variants, generics, writer)?;
+                    } else if self.options.generate_enums_as_sum_types {
+                        self.generate_sum_type_enum(name, variants, generics, writer)?;
+                    } else {
+                        self.generate_enum(name, variants, writer)?;
+                    }
                 } else {
-                    self.generate_enum(name, variants, writer)?;
+                    // Data-carrying variants: emit a discriminated union.
+                    self.generate_tagged_union(name, variants, tagging, generics, writer)?;
                 }
             }
-            TypescriptType::Trait { name, methods, doc } => {
+            TypescriptType::Trait { name, methods, generics, doc } => {
                 self.write_doc(doc, writer)?;
-                writeln!(writer, "export interface {} {{", name)?;
-                
-                let mut method_names: Vec<_> = methods.iter().collect();
-                if self.options.sort_alphabetically {
-                    method_names.sort_by(|a, b| a.name.cmp(&b.name));
-                }
+                if self.options.generate_rpc_client {
+                    self.generate_rpc_client(name, methods, generics, writer)?;
+                } else {
+                    writeln!(writer, "export interface {}{} {{", name, generic_suffix(generics))?;
 
-                for method in method_names {
-                    self.generate_method(method, writer)?;
+                    let mut method_names: Vec<_> = methods.iter().collect();
+                    if self.options.sort_alphabetically {
+                        method_names.sort_by(|a, b| a.name.cmp(&b.name));
+                    }
+
+                    for method in method_names {
+                        self.generate_method(method, writer)?;
+                    }
+                    writeln!(writer, "}}")?;
                 }
-                writeln!(writer, "}}")?;
+            }
+            TypescriptType::TypeAlias { name, target, generics, doc } => {
+                self.write_doc(doc, writer)?;
+                writeln!(writer, "export type {}{} = {};", name, generic_suffix(generics), target)?;
             }
         }
         Ok(())
@@ -126,40 +207,350 @@ impl Generator {
         Ok(())
     }
 
-    fn generate_enum<W: Write>(&self, name: &str, variants: &[String], writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
+    fn generate_enum<W: Write>(&self, name: &str, variants: &[TypescriptVariant], writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
         writeln!(writer, "export enum {} {{", name)?;
         for (i, variant) in variants.iter().enumerate() {
-            writeln!(writer, "    {} = {},", variant, i)?;
+            self.write_doc(&variant.doc, writer)?;
+            let value = match &variant.value {
+                Some(val) => val.to_typescript(),
+                None => i.to_string(),
+            };
+            writeln!(writer, "    {} = {},", variant.name, value)?;
         }
         writeln!(writer, "}}")?;
         Ok(())
     }
 
-    fn generate_sum_type_enum<W: Write>(&self, name: &str, variants: &[String], writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
-        write!(writer, "export type {} =", name)?;
+    fn generate_const_enum<W: Write>(&self, name: &str, variants: &[TypescriptVariant], generics: &[String], writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
+        writeln!(writer, "export const {} = {{", name)?;
+        for variant in variants {
+            self.write_doc(&variant.doc, writer)?;
+            // Preserve an explicit discriminant value; default to the name.
+            let value = match &variant.value {
+                Some(val) => val.to_typescript(),
+                None => format!("\"{}\"", variant.name),
+            };
+            writeln!(writer, "    {}: {},", variant.name, value)?;
+        }
+        writeln!(writer, "}} as const;")?;
+        // The value lives in the runtime object; the generic parameters ride on
+        // the derived type alias.
+        writeln!(writer, "export type {}{} = typeof {}[keyof typeof {}];", name, generic_suffix(generics), name, name)?;
+        Ok(())
+    }
+
+    fn generate_sum_type_enum<W: Write>(&self, name: &str, variants: &[TypescriptVariant], generics: &[String], writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
+        write!(writer, "export type {}{} =", name, generic_suffix(generics))?;
         for (i, variant) in variants.iter().enumerate() {
             if i == 0 {
                 writeln!(writer)?;
-                write!(writer, "    \"{}\"", variant)?;
             } else {
                 writeln!(writer, " |")?;
-                write!(writer, "    \"{}\"", variant)?;
+            }
+            if variant.doc.is_some() {
+                self.write_doc(&variant.doc, writer)?;
+            }
+            match &variant.value {
+                Some(val) => write!(writer, "    {}", val.to_typescript())?,
+                None => write!(writer, "    \"{}\"", variant.name)?,
             }
         }
         writeln!(writer, ";")?;
         Ok(())
     }
 
+    fn generate_rpc_client<W: Write>(
+        &self,
+        name: &str,
+        methods: &[TypescriptMethod],
+        generics: &[String],
+        writer: &mut W,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        writeln!(writer, "export class {}Client{} {{", name, generic_suffix(generics))?;
+        writeln!(
+            writer,
+            "    constructor(private readonly send: (method: string, params: unknown) => Promise<unknown>) {{}}"
+        )?;
+
+        let mut method_list: Vec<_> = methods.iter().collect();
+        if self.options.sort_alphabetically {
+            method_list.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        for method in method_list {
+            self.write_doc(&method.doc, writer)?;
+            let signature: Vec<String> = method
+                .params
+                .iter()
+                .map(|p| format!("{}: {}", p.name, p.ts_type))
+                .collect();
+            let result = if method.return_type == "void" {
+                "void".to_string()
+            } else {
+                method.return_type.clone()
+            };
+            // Marshal params either by name (object) or positionally (array).
+            let params = if self.options.rpc_params_by_name {
+                format!("{{ {} }}", method.params.iter().map(|p| p.name.clone()).collect::<Vec<_>>().join(", "))
+            } else {
+                format!("[{}]", method.params.iter().map(|p| p.name.clone()).collect::<Vec<_>>().join(", "))
+            };
+            writeln!(
+                writer,
+                "    async {}({}): Promise<{}> {{",
+                method.name,
+                signature.join(", "),
+                result
+            )?;
+            writeln!(
+                writer,
+                "        return (await this.send(\"{}\", {})) as {};",
+                method.name, params, result
+            )?;
+            writeln!(writer, "    }}")?;
+        }
+
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
+
+    fn generate_tagged_union<W: Write>(
+        &self,
+        name: &str,
+        variants: &[TypescriptVariant],
+        tagging: &EnumTagging,
+        generics: &[String],
+        writer: &mut W,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // An explicit discriminant overrides the serde-derived tagging with an
+        // internally-tagged union (`{ kind: "Circle", radius: number }`).
+        let effective = match &self.options.tagged_union_discriminant {
+            Some(tag) => EnumTagging::Internal { tag: tag.clone() },
+            None => tagging.clone(),
+        };
+
+        let members: Vec<TsType> = variants
+            .iter()
+            .map(|v| self.variant_type(v, &effective))
+            .collect();
+
+        write!(writer, "export type {}{} =", name, generic_suffix(generics))?;
+        for (i, (member, variant)) in members.iter().zip(variants.iter()).enumerate() {
+            if i == 0 {
+                writeln!(writer)?;
+            } else {
+                writeln!(writer, " |")?;
+            }
+            if variant.doc.is_some() {
+                self.write_doc(&variant.doc, writer)?;
+            }
+            write!(writer, "    {}", member.to_typescript())?;
+        }
+        writeln!(writer, ";")?;
+        Ok(())
+    }
+
+    /// Build the TypeScript type for a single variant under the given tagging.
+    fn variant_type(&self, variant: &TypescriptVariant, tagging: &EnumTagging) -> TsType {
+        let discriminant = TsType::Reference(format!("\"{}\"", variant.name));
+
+        match tagging {
+            EnumTagging::External => match &variant.fields {
+                VariantFields::Unit => discriminant,
+                _ => TsType::Object(vec![prop(&variant.name, self.payload_type(&variant.fields))]),
+            },
+            EnumTagging::Internal { tag } => match &variant.fields {
+                VariantFields::Named(fields) => {
+                    let mut props = vec![prop(tag, discriminant)];
+                    props.extend(fields.iter().map(field_prop));
+                    TsType::Object(props)
+                }
+                // A newtype variant's payload is flattened alongside the tag by
+                // serde (only valid when the inner type is a struct/map), so
+                // model it as an intersection rather than dropping the value.
+                VariantFields::Unnamed(elems) if elems.len() == 1 => {
+                    let tagged = TsType::Object(vec![prop(tag, discriminant)]);
+                    TsType::Reference(format!("{} & {}", tagged.to_typescript(), elems[0]))
+                }
+                // Unit, or a tuple serde cannot internally tag: tag only.
+                _ => TsType::Object(vec![prop(tag, discriminant)]),
+            },
+            EnumTagging::Adjacent { tag, content } => {
+                let mut props = vec![prop(tag, discriminant)];
+                if !matches!(variant.fields, VariantFields::Unit) {
+                    props.push(prop(content, self.payload_type(&variant.fields)));
+                }
+                TsType::Object(props)
+            }
+            EnumTagging::Untagged => match &variant.fields {
+                VariantFields::Unit => TsType::Reference("null".to_string()),
+                _ => self.payload_type(&variant.fields),
+            },
+        }
+    }
+
+    /// The TypeScript type of a variant payload, independent of tagging.
+    fn payload_type(&self, fields: &VariantFields) -> TsType {
+        match fields {
+            VariantFields::Unit => TsType::Reference("null".to_string()),
+            VariantFields::Named(fields) => {
+                TsType::Object(fields.iter().map(field_prop).collect())
+            }
+            VariantFields::Unnamed(elems) => {
+                if elems.len() == 1 {
+                    TsType::Reference(elems[0].clone())
+                } else {
+                    TsType::Reference(format!("[{}]", elems.join(", ")))
+                }
+            }
+        }
+    }
+
     fn write_doc<W: Write>(&self, doc: &Option<String>, writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(doc_text) = doc {
+            let doc_text = crate::extract::unescape_doc(doc_text);
             writeln!(writer, "/**")?;
-            writeln!(writer, " * {}", doc_text)?;
+            for line in doc_text.split('\n') {
+                if line.is_empty() {
+                    writeln!(writer, " *")?;
+                } else {
+                    writeln!(writer, " * {}", line)?;
+                }
+            }
             writeln!(writer, " */")?;
         }
         Ok(())
     }
 }
 
+/// Pipe generated source through an external formatter, returning the formatted
+/// output, or the original source (with a warning) if the command is missing or
+/// fails.
+fn format_source(source: &[u8], command: &FormatterCommand) -> Vec<u8> {
+    use std::process::{Command, Stdio};
+
+    let child = Command::new(&command.program)
+        .args(&command.args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(_) => {
+            eprintln!(
+                "bel: formatter `{}` could not be spawned; emitting unformatted output",
+                command.program
+            );
+            return source.to_vec();
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        if stdin.write_all(source).is_err() {
+            eprintln!("bel: failed to write to formatter stdin; emitting unformatted output");
+            return source.to_vec();
+        }
+    }
+
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => output.stdout,
+        _ => {
+            eprintln!(
+                "bel: formatter `{}` failed; emitting unformatted output",
+                command.program
+            );
+            source.to_vec()
+        }
+    }
+}
+
+/// Order types so dependencies are emitted before their dependents.
+///
+/// Dependency edges come from interface fields only: references made by
+/// enums, traits and type aliases add no edges, so such a type can be emitted
+/// before a type it references. A DFS post-order over the reference graph yields a topological ordering;
+/// edges that would close a cycle are ignored (the types stay as named
+/// references), so cyclic graphs still produce a stable, total order.
+fn topological_order(types: &HashMap<String, TypescriptType>) -> Vec<String> {
+    let deps = |name: &str| -> Vec<String> {
+        match types.get(name) {
+            Some(TypescriptType::Interface { fields, .. }) => fields
+                .iter()
+                .flat_map(|f| crate::extract::referenced_type_names(&f.ts_type))
+                .filter(|d| types.contains_key(d) && d != name)
+                .collect(),
+            _ => Vec::new(),
+        }
+    };
+
+    // Start from names in sorted order for deterministic output.
+    let mut roots: Vec<String> = types.keys().cloned().collect();
+    roots.sort();
+
+    let mut ordered = Vec::new();
+    let mut visited = std::collections::HashSet::new();
+    let mut on_stack = std::collections::HashSet::new();
+
+    fn visit(
+        name: &str,
+        deps: &dyn Fn(&str) -> Vec<String>,
+        visited: &mut std::collections::HashSet<String>,
+        on_stack: &mut std::collections::HashSet<String>,
+        ordered: &mut Vec<String>,
+    ) {
+        if visited.contains(name) {
+            return;
+        }
+        on_stack.insert(name.to_string());
+        let mut children = deps(name);
+        children.sort();
+        for child in children {
+            if !on_stack.contains(&child) {
+                visit(&child, deps, visited, on_stack, ordered);
+            }
+        }
+        on_stack.remove(name);
+        visited.insert(name.to_string());
+        ordered.push(name.to_string());
+    }
+
+    for root in &roots {
+        visit(root, &deps, &mut visited, &mut on_stack, &mut ordered);
+    }
+    ordered
+}
+
+/// Render a TypeScript type-parameter list (`<T, U>`), or empty when there are none.
+fn generic_suffix(generics: &[String]) -> String {
+    if generics.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generics.join(", "))
+    }
+}
+
+/// Build a required, non-readonly object property from a name and type.
+fn prop(name: &str, ts_type: TsType) -> TsProperty {
+    TsProperty {
+        name: name.to_string(),
+        ts_type,
+        optional: false,
+        readonly: false,
+    }
+}
+
+/// Build an object property from an extracted field, preserving optionality.
+fn field_prop(field: &TypescriptField) -> TsProperty {
+    TsProperty {
+        name: field.name.clone(),
+        ts_type: TsType::Reference(field.ts_type.clone()),
+        optional: field.optional,
+        readonly: false,
+    }
+}
+
 /// Convenience function to generate TypeScript code
 pub fn generate<W: Write>(types: &HashMap<String, TypescriptType>, options: GeneratorOptions, writer: &mut W) -> Result<(), Box<dyn std::error::Error>> {
     let generator = Generator::new(options);
@@ -169,7 +560,10 @@ pub fn generate<W: Write>(types: &HashMap<String, TypescriptType>, options: Gene
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::extract::{TypescriptField, TypescriptType};
+    use crate::extract::{
+        EnumTagging, TypescriptField, TypescriptMethod, TypescriptParam, TypescriptType,
+        TypescriptVariant, VariantFields,
+    };
     use std::collections::HashMap;
 
     #[test]
@@ -191,13 +585,14 @@ mod tests {
                     doc: None,
                 },
             ],
+            generics: vec![],
             doc: None,
         });
 
         let mut output = Vec::new();
         generate(&types, GeneratorOptions::default(), &mut output).unwrap();
         let result = String::from_utf8(output).unwrap();
-        
+
         assert!(result.contains("export interface Demo"));
         assert!(result.contains("foo?: string"));
         assert!(result.contains("bar: number"));
@@ -208,16 +603,273 @@ mod tests {
         let mut types = HashMap::new();
         types.insert("Status".to_string(), TypescriptType::Enum {
             name: "Status".to_string(),
-            variants: vec!["Active".to_string(), "Inactive".to_string()],
+            variants: vec![
+                TypescriptVariant { name: "Active".to_string(), value: None, fields: VariantFields::Unit, doc: None },
+                TypescriptVariant { name: "Inactive".to_string(), value: None, fields: VariantFields::Unit, doc: None },
+            ],
+            tagging: EnumTagging::External,
+            generics: vec![],
             doc: None,
         });
 
         let mut output = Vec::new();
         generate(&types, GeneratorOptions::default(), &mut output).unwrap();
         let result = String::from_utf8(output).unwrap();
-        
+
         assert!(result.contains("export enum Status"));
         assert!(result.contains("Active = 0"));
         assert!(result.contains("Inactive = 1"));
     }
+
+    #[test]
+    fn test_generate_const_enum() {
+        let mut types = HashMap::new();
+        types.insert("Status".to_string(), TypescriptType::Enum {
+            name: "Status".to_string(),
+            variants: vec![
+                TypescriptVariant { name: "Active".to_string(), value: None, fields: VariantFields::Unit, doc: None },
+                TypescriptVariant { name: "Inactive".to_string(), value: None, fields: VariantFields::Unit, doc: None },
+            ],
+            tagging: EnumTagging::External,
+            generics: vec![],
+            doc: None,
+        });
+
+        let mut options = GeneratorOptions::default();
+        options.generate_enums_as_const = true;
+
+        let mut output = Vec::new();
+        generate(&types, options, &mut output).unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        assert!(result.contains("export const Status = {"));
+        assert!(result.contains("Active: \"Active\""));
+        assert!(result.contains("} as const;"));
+        assert!(result.contains("export type Status = typeof Status[keyof typeof Status];"));
+    }
+
+    #[test]
+    fn test_const_enum_preserves_explicit_values() {
+        use crate::extract::EnumValue;
+        let mut types = HashMap::new();
+        types.insert("Code".to_string(), TypescriptType::Enum {
+            name: "Code".to_string(),
+            variants: vec![
+                TypescriptVariant { name: "Ok".to_string(), value: Some(EnumValue::Number(200)), fields: VariantFields::Unit, doc: None },
+                TypescriptVariant { name: "NotFound".to_string(), value: Some(EnumValue::Number(404)), fields: VariantFields::Unit, doc: None },
+            ],
+            tagging: EnumTagging::External,
+            generics: vec![],
+            doc: None,
+        });
+
+        let mut options = GeneratorOptions::default();
+        options.generate_enums_as_const = true;
+
+        let mut output = Vec::new();
+        generate(&types, options, &mut output).unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        assert!(result.contains("Ok: 200"));
+        assert!(result.contains("NotFound: 404"));
+    }
+
+    #[test]
+    fn test_tagged_union_discriminant_override() {
+        let mut types = HashMap::new();
+        types.insert("Shape".to_string(), TypescriptType::Enum {
+            name: "Shape".to_string(),
+            variants: vec![TypescriptVariant {
+                name: "Circle".to_string(),
+                value: None,
+                fields: VariantFields::Named(vec![TypescriptField {
+                    name: "radius".to_string(),
+                    ts_type: "number".to_string(),
+                    optional: false,
+                    doc: None,
+                }]),
+                doc: None,
+            }],
+            tagging: EnumTagging::External,
+            generics: vec![],
+            doc: None,
+        });
+
+        let mut options = GeneratorOptions::default();
+        options.tagged_union_discriminant = Some("kind".to_string());
+
+        let mut output = Vec::new();
+        generate(&types, options, &mut output).unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        assert!(result.contains("{ kind: \"Circle\", radius: number }"));
+    }
+
+    #[test]
+    fn test_topological_emission_order() {
+        let mut types = HashMap::new();
+        types.insert("Outer".to_string(), TypescriptType::Interface {
+            name: "Outer".to_string(),
+            fields: vec![TypescriptField {
+                name: "inner".to_string(),
+                ts_type: "Inner".to_string(),
+                optional: false,
+                doc: None,
+            }],
+            generics: vec![],
+            doc: None,
+        });
+        types.insert("Inner".to_string(), TypescriptType::Interface {
+            name: "Inner".to_string(),
+            fields: vec![],
+            generics: vec![],
+            doc: None,
+        });
+
+        let mut output = Vec::new();
+        generate(&types, GeneratorOptions::default(), &mut output).unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        let inner_at = result.find("interface Inner").unwrap();
+        let outer_at = result.find("interface Outer").unwrap();
+        assert!(inner_at < outer_at, "dependency should be declared first");
+    }
+
+    #[test]
+    fn test_generate_rpc_client() {
+        let mut types = HashMap::new();
+        types.insert("Api".to_string(), TypescriptType::Trait {
+            name: "Api".to_string(),
+            methods: vec![TypescriptMethod {
+                name: "say_hello".to_string(),
+                params: vec![TypescriptParam {
+                    name: "name".to_string(),
+                    ts_type: "string".to_string(),
+                }],
+                return_type: "string".to_string(),
+                doc: None,
+            }],
+            generics: vec![],
+            doc: None,
+        });
+
+        let mut options = GeneratorOptions::default();
+        options.generate_rpc_client = true;
+
+        let mut output = Vec::new();
+        generate(&types, options, &mut output).unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        assert!(result.contains("export class ApiClient"));
+        assert!(result.contains("async say_hello(name: string): Promise<string>"));
+        assert!(result.contains("this.send(\"say_hello\", [name])"));
+    }
+
+    #[test]
+    fn test_generate_external_tagged_union() {
+        let mut types = HashMap::new();
+        types.insert("Msg".to_string(), TypescriptType::Enum {
+            name: "Msg".to_string(),
+            variants: vec![
+                TypescriptVariant {
+                    name: "Text".to_string(),
+                    value: None,
+                    fields: VariantFields::Named(vec![TypescriptField {
+                        name: "body".to_string(),
+                        ts_type: "string".to_string(),
+                        optional: false,
+                        doc: None,
+                    }]),
+                    doc: None,
+                },
+                TypescriptVariant {
+                    name: "Ping".to_string(),
+                    value: None,
+                    fields: VariantFields::Unnamed(vec!["number".to_string()]),
+                    doc: None,
+                },
+            ],
+            tagging: EnumTagging::External,
+            generics: vec![],
+            doc: None,
+        });
+
+        let mut output = Vec::new();
+        generate(&types, GeneratorOptions::default(), &mut output).unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        assert!(result.contains("export type Msg ="));
+        assert!(result.contains("{ Text: { body: string } }"));
+        assert!(result.contains("{ Ping: number }"));
+    }
+
+    #[test]
+    fn test_generic_enum_header_survives() {
+        // A generic enum must reference its parameters, so the union header has
+        // to declare them or the output fails to typecheck.
+        let source = r#"
+            pub enum Either<L, R> {
+                Left(L),
+                Right(R),
+            }
+        "#;
+
+        let result = crate::extract_and_generate(
+            source,
+            crate::extract::ExtractOptions::default(),
+            GeneratorOptions::default(),
+        )
+        .unwrap();
+
+        assert!(result.contains("export type Either<L, R> ="));
+        assert!(result.contains("{ Left: L }"));
+        assert!(result.contains("{ Right: R }"));
+    }
+
+    #[test]
+    fn test_internal_tag_preserves_newtype_payload() {
+        let mut types = HashMap::new();
+        types.insert("Msg".to_string(), TypescriptType::Enum {
+            name: "Msg".to_string(),
+            variants: vec![TypescriptVariant {
+                name: "Ping".to_string(),
+                value: None,
+                fields: VariantFields::Unnamed(vec!["Heartbeat".to_string()]),
+                doc: None,
+            }],
+            tagging: EnumTagging::Internal { tag: "type".to_string() },
+            generics: vec![],
+            doc: None,
+        });
+
+        let mut output = Vec::new();
+        generate(&types, GeneratorOptions::default(), &mut output).unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        // The payload must survive rather than collapse to `{ type: "Ping" }`.
+        assert!(result.contains("{ type: \"Ping\" } & Heartbeat"));
+    }
+
+    #[test]
+    fn test_rename_all_discriminant_is_renamed() {
+        // A renamed data-carrying enum must carry the renamed discriminant
+        // string all the way through to the union member.
+        let source = r#"
+            #[serde(rename_all = "snake_case")]
+            pub enum Event {
+                UserJoined { id: u32 },
+                Ping(u32),
+            }
+        "#;
+
+        let result = crate::extract_and_generate(
+            source,
+            crate::extract::ExtractOptions::default(),
+            GeneratorOptions::default(),
+        )
+        .unwrap();
+
+        assert!(result.contains("{ user_joined: { id: number } }"));
+        assert!(result.contains("{ ping: number }"));
+    }
 }
@@ -7,7 +7,6 @@
 pub mod extract;
 pub mod generator;
 pub mod typescript;
-pub mod enums;
 
 pub use extract::*;
 pub use generator::*;